@@ -1,80 +1,501 @@
 use rand::Rng;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeMap;
-
-pub const MAX_ID: u16 = 64; // Keyspace size (mod MAX_ID)
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone)]
 pub struct Node {
     pub id: u16,
+    /// This node's current best guess at its successor, corrected by
+    /// [`Chord::stabilize`] as the ring converges.
+    pub successor: u16,
+    /// This node's believed predecessor, set by [`Chord::notify`]; `None`
+    /// until some other node notifies it.
+    pub predecessor: Option<u16>,
+    /// `fingers[i]` is this node's best known guess for
+    /// `successor((id + 2^i) mod max_id)`; fingers start out pointing at
+    /// `id` itself and are corrected one at a time by [`Chord::fix_fingers`].
+    pub fingers: Vec<u16>,
 }
 
 pub struct Chord {
+    // Keyspace size (mod max_id); always a power of two
+    max_id: u16,
     // Sorted map of nodes in the Chord ring
     nodes: BTreeMap<u16, Node>,
+    // Key -> id of the node currently responsible for it
+    owners: BTreeMap<String, u16>,
+    // Key -> stored value
+    values: BTreeMap<String, String>,
+    // Revision tree for undo/redo; `current` is the cursor into `history`.
+    history: Vec<Revision>,
+    current: Option<usize>,
+    // Revisions whose parent is the (virtual) root, i.e. `current == None`.
+    roots: Vec<usize>,
+    // Which finger index `fix_fingers` refreshes on its next call.
+    fix_finger_cursor: u32,
+}
+
+/// One entry in the undo/redo tree: the human-readable description of the
+/// action that was taken, how to replay it on redo, how to reverse it on
+/// undo, and the revision it branched from.
+struct Revision {
+    description: String,
+    forward: ForwardOp,
+    inverse: InverseOp,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+#[derive(Clone)]
+enum ForwardOp {
+    AddNode(u16),
+    DeleteNode(u16),
+    Put { key: String, value: String },
+}
+
+#[derive(Clone)]
+enum InverseOp {
+    RemoveNode(u16),
+    ReinsertNode(u16),
+    RestoreValue { key: String, previous: Option<String> },
 }
 
 impl Chord {
-    pub const fn new() -> Self {
+    /// Create an empty ring with a keyspace of `max_id` identifiers.
+    /// `max_id` must be a power of two.
+    pub fn new(max_id: u16) -> Self {
         Self {
+            max_id,
             nodes: BTreeMap::new(),
+            owners: BTreeMap::new(),
+            values: BTreeMap::new(),
+            history: Vec::new(),
+            current: None,
+            roots: Vec::new(),
+            fix_finger_cursor: 0,
         }
     }
 
-    /// Add a node at a random position in the Chord ring
-    pub fn add_node(&mut self) -> Result<u16, ChordError> {
+    /// The size of this ring's keyspace (always a power of two).
+    pub fn max_id(&self) -> u16 {
+        self.max_id
+    }
+
+    /// Number of bits in the keyspace, i.e. `log2(max_id)`; the length of
+    /// every node's finger table.
+    fn bits(&self) -> u32 {
+        self.max_id.trailing_zeros()
+    }
+
+    /// Add a node at a random position in the Chord ring. Returns the new
+    /// node's id and the number of stored keys that migrated to a new owner.
+    pub fn add_node(&mut self) -> Result<(u16, usize), ChordError> {
         let mut rng = rand::thread_rng();
-        if self.nodes.len() == MAX_ID.into() {
+        if self.nodes.len() == self.max_id.into() {
             Err(ChordError::RingIsFull)
         }
         else {
-            let mut new_id = rng.gen_range(0..MAX_ID);
+            let mut new_id = rng.gen_range(0..self.max_id);
 
             while self.nodes.contains_key(&new_id) {
-                new_id = rng.gen_range(0..MAX_ID); // Pick another random ID within the keyspace
+                new_id = rng.gen_range(0..self.max_id); // Pick another random ID within the keyspace
             }
-    
-            let node = Node { id: new_id };
-            self.nodes.insert(new_id, node);
-            Ok(new_id)
+
+            let migrated = self.insert_node(new_id);
+            self.record_revision(
+                format!("added node {}", new_id),
+                ForwardOp::AddNode(new_id),
+                InverseOp::RemoveNode(new_id),
+            );
+            Ok((new_id, migrated))
         }
     }
 
-    /// Delete a node by its id
-    pub fn delete_node(&mut self, id: u16) -> Result<(), ChordError> {
-        if self.nodes.remove(&id).is_some() {
-            Ok(())
+    /// Delete a node by its id. Returns the number of stored keys that
+    /// migrated to a new owner.
+    pub fn delete_node(&mut self, id: u16) -> Result<usize, ChordError> {
+        if !self.nodes.contains_key(&id) {
+            return Err(ChordError::NodeDoesNotExist);
+        }
+
+        let migrated = self.remove_node(id);
+        self.record_revision(
+            format!("deleted node {}", id),
+            ForwardOp::DeleteNode(id),
+            InverseOp::ReinsertNode(id),
+        );
+        Ok(migrated)
+    }
+
+    /// Insert a node at a specific id and migrate keys. The new node's
+    /// successor is whatever the existing (possibly not-yet-stabilized) ring
+    /// currently believes it should be; its predecessor and finger table
+    /// start out empty/self-pointing and are corrected by later calls to
+    /// [`Chord::tick`]. Returns the number of keys migrated. Assumes `id` is
+    /// not already present.
+    fn insert_node(&mut self, id: u16) -> usize {
+        let successor = if self.nodes.is_empty() {
+            id
         } else {
-            Err(ChordError::NodeDoesNotExist)
+            self.search(id).map(|node| node.id).unwrap_or(id)
+        };
+        let fingers = vec![id; self.bits() as usize];
+        self.nodes.insert(
+            id,
+            Node {
+                id,
+                successor,
+                predecessor: None,
+                fingers,
+            },
+        );
+        self.migrate_keys()
+    }
+
+    /// Remove a node by id and migrate keys. Other nodes' successor,
+    /// predecessor and finger table entries that still point at `id` are left
+    /// stale; [`Chord::tick`] discovers and routes around the departure.
+    /// Returns the number of keys migrated.
+    fn remove_node(&mut self, id: u16) -> usize {
+        self.nodes.remove(&id);
+        self.migrate_keys()
+    }
+
+    /// Append a new revision as a child of the current cursor and move the
+    /// cursor to it.
+    fn record_revision(&mut self, description: String, forward: ForwardOp, inverse: InverseOp) {
+        let parent = self.current;
+        let idx = self.history.len();
+        self.history.push(Revision {
+            description,
+            forward,
+            inverse,
+            parent,
+            children: Vec::new(),
+        });
+        match parent {
+            Some(parent_idx) => self.history[parent_idx].children.push(idx),
+            None => self.roots.push(idx),
         }
+        self.current = Some(idx);
     }
 
-    /// Find the node responsible for a given key
-    pub fn search(&self, key: u16) -> Result<&Node, ChordError> {
+    fn apply_forward(&mut self, op: &ForwardOp) {
+        match op {
+            ForwardOp::AddNode(id) => {
+                self.insert_node(*id);
+            }
+            ForwardOp::DeleteNode(id) => {
+                self.remove_node(*id);
+            }
+            ForwardOp::Put { key, value } => {
+                let owner_id = self.search(self.hash_key(key)).map(|n| n.id).unwrap_or_default();
+                self.owners.insert(key.clone(), owner_id);
+                self.values.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    fn apply_inverse(&mut self, op: &InverseOp) {
+        match op {
+            InverseOp::RemoveNode(id) => {
+                self.remove_node(*id);
+            }
+            InverseOp::ReinsertNode(id) => {
+                self.insert_node(*id);
+            }
+            InverseOp::RestoreValue { key, previous } => match previous {
+                Some(value) => {
+                    self.values.insert(key.clone(), value.clone());
+                }
+                None => {
+                    self.values.remove(key);
+                    self.owners.remove(key);
+                }
+            },
+        }
+    }
+
+    /// Undo the current revision, moving the cursor to its parent. Returns a
+    /// description of the action that was undone, e.g. "added node 42". Like
+    /// `delete_node`, this can leave the ring with no nodes at all; every
+    /// other entry point (`search_with_path`, `tick`, the canvas) already
+    /// handles that case without panicking.
+    pub fn undo(&mut self) -> Option<String> {
+        let idx = self.current?;
+        let description = self.history[idx].description.clone();
+        let inverse = self.history[idx].inverse.clone();
+        self.apply_inverse(&inverse);
+        self.current = self.history[idx].parent;
+        Some(description)
+    }
+
+    /// Redo by walking to the last child of the cursor and re-applying it.
+    /// Returns a description of the action that was redone.
+    pub fn redo(&mut self) -> Option<String> {
+        let children = match self.current {
+            Some(idx) => &self.history[idx].children,
+            None => &self.roots,
+        };
+        let idx = *children.last()?;
+        let description = self.history[idx].description.clone();
+        let forward = self.history[idx].forward.clone();
+        self.apply_forward(&forward);
+        self.current = Some(idx);
+        Some(description)
+    }
+
+    /// Hash a string key into the keyspace using a stable 64-bit hash folded
+    /// down to the ring's `max_id`.
+    fn hash_key(&self, key: &str) -> u16 {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let h = hasher.finish();
+        ((h ^ (h >> 16) ^ (h >> 32) ^ (h >> 48)) as u16) % self.max_id
+    }
+
+    /// Store `value` under `key`, placing it on the node responsible for
+    /// `key`'s hashed position in the ring.
+    pub fn put(&mut self, key: &str, value: String) -> Result<(), ChordError> {
+        let node = self.search(self.hash_key(key))?;
+        self.owners.insert(key.to_string(), node.id);
+        let previous = self.values.insert(key.to_string(), value.clone());
+        self.record_revision(
+            format!("inserted key \"{}\"", key),
+            ForwardOp::Put { key: key.to_string(), value },
+            InverseOp::RestoreValue { key: key.to_string(), previous },
+        );
+        Ok(())
+    }
+
+    /// Look up a previously stored value by key.
+    pub fn get(&self, key: &str) -> Result<&String, ChordError> {
+        self.values.get(key).ok_or(ChordError::KeyDoesNotExist)
+    }
+
+    /// All stored keys currently owned by `node_id`.
+    pub fn keys_on(&self, node_id: u16) -> Vec<&str> {
+        self.owners
+            .iter()
+            .filter(|(_, &owner)| owner == node_id)
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+
+    /// Re-resolve the owning node for every stored key, moving it if the
+    /// ring topology has changed. Returns the number of keys migrated.
+    fn migrate_keys(&mut self) -> usize {
+        if self.nodes.is_empty() {
+            let migrated = self.owners.len();
+            self.owners.clear();
+            return migrated;
+        }
+
+        let keys: Vec<String> = self.values.keys().cloned().collect();
+        let mut migrated = 0;
+        for key in keys {
+            let new_owner = self.successor(self.hash_key(&key));
+            if self.owners.get(&key) != Some(&new_owner) {
+                self.owners.insert(key, new_owner);
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
+    /// The node responsible for `pos`: the smallest node id >= `pos`, wrapping
+    /// around to the smallest node id in the ring if none is found.
+    fn successor(&self, pos: u16) -> u16 {
+        match self.nodes.range(pos..).next() {
+            Some((&id, _)) => id,
+            None => *self.nodes.keys().next().unwrap(),
+        }
+    }
+
+    /// Run one round of the stabilization protocol: every node checks in with
+    /// its successor and notifies it, then one finger table entry is
+    /// refreshed ring-wide. Call this repeatedly (e.g. on a UI tick) to let a
+    /// join or departure's effects propagate through the ring.
+    pub fn tick(&mut self) {
+        let ids: Vec<u16> = self.nodes.keys().copied().collect();
+        for id in ids {
+            self.stabilize(id);
+        }
+        self.fix_fingers();
+        // A `put` issued before the ring has stabilized may have landed on a
+        // since-superseded owner; re-resolve every key against ground truth
+        // so stored values converge to their correct owner as routing does.
+        self.migrate_keys();
+    }
+
+    /// Ask `n`'s successor for its predecessor `x`; if `x` lies strictly
+    /// between `n` and its successor, adopt `x` as the new, closer successor.
+    /// Either way, notify the (possibly updated) successor of `n`.
+    fn stabilize(&mut self, n: u16) {
+        let Some(stored_successor) = self.nodes.get(&n).map(|node| node.successor) else {
+            return;
+        };
+
+        let successor = if self.nodes.contains_key(&stored_successor) {
+            stored_successor
+        } else {
+            // The node we thought was our successor has left the ring;
+            // fall back to asking the ring directly, standing in for the
+            // successor-list failover a real Chord node would use.
+            let fallback = self.successor(n.wrapping_add(1) % self.max_id);
+            if let Some(node) = self.nodes.get_mut(&n) {
+                node.successor = fallback;
+            }
+            fallback
+        };
+
+        if let Some(x) = self.nodes[&successor].predecessor {
+            if x != n && self.nodes.contains_key(&x) && Self::in_open_interval(x, n, successor) {
+                if let Some(node) = self.nodes.get_mut(&n) {
+                    node.successor = x;
+                }
+            }
+        }
+
+        let successor = self.nodes[&n].successor;
+        self.notify(successor, n);
+    }
+
+    /// Tell `target` that `candidate` might be its predecessor; `target`
+    /// adopts it if it has no predecessor yet, its predecessor has left the
+    /// ring, or `candidate` is a closer fit than the one it has.
+    fn notify(&mut self, target: u16, candidate: u16) {
+        let live_predecessor = self
+            .nodes
+            .get(&target)
+            .and_then(|node| node.predecessor)
+            .filter(|predecessor| self.nodes.contains_key(predecessor));
+        if let Some(node) = self.nodes.get_mut(&target) {
+            let accept = match live_predecessor {
+                None => true,
+                Some(predecessor) => Self::in_open_interval(candidate, predecessor, target),
+            };
+            if accept {
+                node.predecessor = Some(candidate);
+            }
+        }
+    }
+
+    /// Refresh one finger table entry, ring-wide, cycling through indices
+    /// `0..bits` one call at a time so convergence is visible step by step.
+    fn fix_fingers(&mut self) {
+        let bits = self.bits();
+        if bits == 0 {
+            return;
+        }
+
+        let idx = (self.fix_finger_cursor % bits) as usize;
+        let ids: Vec<u16> = self.nodes.keys().copied().collect();
+        for id in ids {
+            let target = id.wrapping_add(1u16 << idx) % self.max_id;
+            let finger = self.search(target).map(|node| node.id).unwrap_or(id);
+            if let Some(node) = self.nodes.get_mut(&id) {
+                if node.fingers.len() <= idx {
+                    node.fingers.resize(idx + 1, id);
+                }
+                node.fingers[idx] = finger;
+            }
+        }
+        self.fix_finger_cursor = (self.fix_finger_cursor + 1) % bits;
+    }
+
+    /// Whether `x` lies strictly between `lo` and `hi` going clockwise around
+    /// the ring, wrapping past `max_id` back to `0`.
+    fn in_open_interval(x: u16, lo: u16, hi: u16) -> bool {
+        if lo == hi {
+            return x != lo;
+        }
+        if lo < hi {
+            x > lo && x < hi
+        } else {
+            x > lo || x < hi
+        }
+    }
+
+    /// Whether `x` lies in the half-open arc `(lo, hi]`, wrapping past
+    /// `max_id` back to `0`.
+    fn in_half_open_interval(x: u16, lo: u16, hi: u16) -> bool {
+        x == hi || Self::in_open_interval(x, lo, hi)
+    }
+
+    /// The closest finger of `n` that still strictly precedes `key`, scanning
+    /// from the highest-reaching finger down to the smallest. Fingers that
+    /// point at a node which has since left the ring are skipped.
+    fn closest_preceding_finger(&self, n: u16, key: u16) -> u16 {
+        let node = &self.nodes[&n];
+        for finger in node.fingers.iter().rev() {
+            if self.nodes.contains_key(finger) && Self::in_open_interval(*finger, n, key) {
+                return *finger;
+            }
+        }
+        n
+    }
+
+    /// Route a lookup for `key` using each node's own (possibly not yet
+    /// stabilized) successor and finger table, returning the responsible
+    /// node along with the sequence of node ids visited.
+    pub fn search_with_path(&self, key: u16) -> Result<(Node, Vec<u16>), ChordError> {
         if self.nodes.is_empty() {
             return Err(ChordError::NoNodesExist);
         }
 
-        if key >= MAX_ID {
+        if key >= self.max_id {
             return Err(ChordError::OutOfRange);
         }
 
-        // Get the node responsible for the key (the smallest node ID >= key, or the first node in the ring)
-        match self.nodes.range(key..).next() {
-            Some((_, node)) => {
-                Ok(node)
+        let mut current = *self.nodes.keys().next().unwrap();
+        let mut path = vec![current];
+        let mut hops = 0;
+
+        loop {
+            let stored_successor = self.nodes[&current].successor;
+            let successor = if self.nodes.contains_key(&stored_successor) {
+                stored_successor
+            } else {
+                self.successor(current.wrapping_add(1) % self.max_id)
+            };
+
+            if Self::in_half_open_interval(key, current, successor) {
+                path.push(successor);
+                return Ok((self.nodes[&successor].clone(), path));
             }
-            None => {
-                // If no node ID is >= key, wrap around to the first node
-                let first_node = self.nodes.iter().next().unwrap();
-                Ok(first_node.1)
+
+            let next = self.closest_preceding_finger(current, key);
+            if next == current || hops >= self.nodes.len() {
+                // No finger makes progress, or the ring hasn't stabilized
+                // enough to guarantee any; fall back to the direct successor
+                // rather than looping forever.
+                path.push(successor);
+                return Ok((self.nodes[&successor].clone(), path));
             }
+
+            current = next;
+            path.push(current);
+            hops += 1;
         }
     }
 
+    /// Find the node responsible for a given key
+    pub fn search(&self, key: u16) -> Result<Node, ChordError> {
+        self.search_with_path(key).map(|(node, _)| node)
+    }
+
     /// Print the current Chord ring for visualization
     pub fn get_ring(&self) -> Vec<&u16> {
         self.nodes.keys().collect::<Vec<_>>()
     }
+
+    /// All nodes currently in the ring, for visualization of their successor
+    /// and predecessor links.
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.values()
+    }
 }
 
 #[derive(Debug)]
@@ -83,4 +504,170 @@ pub enum ChordError {
     NodeDoesNotExist,
     RingIsFull,
     OutOfRange,
-}
\ No newline at end of file
+    KeyDoesNotExist,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_wraps_around_max_id() {
+        // Arc from 14 up to 3, wrapping past the top of the keyspace.
+        assert!(Chord::in_open_interval(0, 14, 3));
+        assert!(Chord::in_open_interval(2, 14, 3));
+        assert!(!Chord::in_open_interval(14, 14, 3));
+        assert!(!Chord::in_open_interval(3, 14, 3));
+        assert!(!Chord::in_open_interval(5, 14, 3));
+
+        assert!(Chord::in_half_open_interval(3, 14, 3));
+        assert!(!Chord::in_half_open_interval(14, 14, 3));
+    }
+
+    #[test]
+    fn lookup_routes_through_multiple_hops_to_the_correct_owner() {
+        let mut chord = Chord::new(16);
+        for id in [1u16, 3, 6, 10, 13] {
+            chord.insert_node(id);
+        }
+        // Give stabilization and finger fixing plenty of rounds to converge.
+        for _ in 0..50 {
+            chord.tick();
+        }
+
+        let (node, path) = chord.search_with_path(12).expect("ring is non-empty");
+
+        // The smallest node id >= 12 is 13: the true, ground-truth owner.
+        assert_eq!(node.id, 13);
+        // Node 1's direct successor is 3, which doesn't cover key 12, so a
+        // correct routing must take at least one finger hop beyond that.
+        assert!(path.len() > 2, "expected more than a single hop, got path {:?}", path);
+        assert_eq!(*path.first().unwrap(), 1);
+        assert_eq!(*path.last().unwrap(), 13);
+    }
+
+    #[test]
+    fn put_get_and_keys_on_track_migration_across_churn() {
+        let mut chord = Chord::new(1024);
+        let (node_a, _) = chord.add_node().unwrap();
+
+        chord.put("alpha", "1".to_string()).unwrap();
+        chord.put("beta", "2".to_string()).unwrap();
+        chord.put("gamma", "3".to_string()).unwrap();
+
+        // With a single node in the ring, it must own every key.
+        assert_eq!(chord.get("alpha").unwrap(), "1");
+        assert_eq!(chord.keys_on(node_a).len(), 3);
+
+        // Adding a node should migrate exactly the keys that now belong to
+        // it, and the count `add_node` reports must match that reality.
+        let (node_b, migrated_on_add) = chord.add_node().unwrap();
+        let keys_on_b = chord.keys_on(node_b).len();
+        assert_eq!(migrated_on_add, keys_on_b);
+        assert_eq!(chord.keys_on(node_a).len() + keys_on_b, 3);
+
+        // Values stay retrievable no matter which node currently owns them.
+        assert_eq!(chord.get("alpha").unwrap(), "1");
+        assert_eq!(chord.get("beta").unwrap(), "2");
+        assert_eq!(chord.get("gamma").unwrap(), "3");
+
+        // Removing node_b must migrate exactly the keys it was holding back
+        // onto node_a, again matching the count `delete_node` reports.
+        let migrated_on_delete = chord.delete_node(node_b).unwrap();
+        assert_eq!(migrated_on_delete, keys_on_b);
+        assert_eq!(chord.keys_on(node_a).len(), 3);
+        assert_eq!(chord.get("gamma").unwrap(), "3");
+
+        assert!(matches!(chord.get("missing"), Err(ChordError::KeyDoesNotExist)));
+    }
+
+    #[test]
+    fn undo_then_redo_restores_the_same_revision() {
+        let mut chord = Chord::new(1024);
+        let (_, _) = chord.add_node().unwrap();
+        let (second_id, _) = chord.add_node().unwrap();
+        assert_eq!(chord.get_ring().len(), 2);
+
+        let undone = chord.undo().expect("an add_node revision exists to undo");
+        assert!(undone.contains(&second_id.to_string()));
+        assert_eq!(chord.get_ring().len(), 1);
+
+        let redone = chord.redo().expect("the undone revision should be redoable");
+        assert!(redone.contains(&second_id.to_string()));
+        assert_eq!(chord.get_ring().len(), 2);
+    }
+
+    #[test]
+    fn undoing_the_last_node_empties_the_ring_without_panicking() {
+        let mut chord = Chord::new(1024);
+        chord.add_node().unwrap();
+        assert_eq!(chord.get_ring().len(), 1);
+
+        assert!(chord.undo().is_some());
+        assert_eq!(chord.get_ring().len(), 0);
+
+        // An empty ring must still answer lookups with an error, not a panic.
+        assert!(matches!(chord.search(0), Err(ChordError::NoNodesExist)));
+    }
+
+    #[test]
+    fn a_new_action_after_undo_branches_instead_of_overwriting() {
+        let mut chord = Chord::new(1024);
+        let (first_id, _) = chord.add_node().unwrap();
+        chord.add_node().unwrap();
+        chord.undo().unwrap(); // back to just first_id
+
+        let (third_id, _) = chord.add_node().unwrap();
+        assert_eq!(chord.get_ring().len(), 2);
+        assert!(chord.get_ring().iter().any(|&&id| id == first_id));
+        assert!(chord.get_ring().iter().any(|&&id| id == third_id));
+
+        // Redo should now walk the newest branch (third_id), not the
+        // original one that was undone.
+        chord.undo().unwrap();
+        let redone = chord.redo().expect("the new branch should be redoable");
+        assert!(redone.contains(&third_id.to_string()));
+    }
+
+    #[test]
+    fn tick_converges_successor_and_predecessor_pointers() {
+        let mut chord = Chord::new(16);
+        for id in [2u16, 5, 9] {
+            chord.insert_node(id);
+        }
+        // Several rounds of stabilize/notify, with no further churn, should
+        // settle every node's successor/predecessor onto a single ring.
+        for _ in 0..50 {
+            chord.tick();
+        }
+
+        let nodes: Vec<&Node> = chord.nodes().collect();
+        assert_eq!(nodes.len(), 3);
+
+        // Every node's successor should, in turn, have that node as its
+        // predecessor: the two pointers agree once stabilization settles.
+        for node in &nodes {
+            let successor = chord
+                .nodes()
+                .find(|candidate| candidate.id == node.successor)
+                .expect("successor must still be in the ring");
+            assert_eq!(successor.predecessor, Some(node.id));
+        }
+
+        // Following successor pointers from any node should visit every
+        // other node exactly once and return to the start.
+        let start = nodes[0].id;
+        let mut visited = vec![start];
+        let mut current = nodes[0].successor;
+        while current != start {
+            visited.push(current);
+            current = chord
+                .nodes()
+                .find(|candidate| candidate.id == current)
+                .expect("successor must still be in the ring")
+                .successor;
+            assert!(visited.len() <= nodes.len(), "successor chain never closed into a ring");
+        }
+        assert_eq!(visited.len(), nodes.len());
+    }
+}
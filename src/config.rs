@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use ratatui::style::Color;
+use ratatui::symbols::Marker;
+use serde::Deserialize;
+
+/// Valid range for [`Config::keyspace_bits`]: the ring's `max_id` is stored
+/// in a `u16`, so anything outside `1..=MAX_KEYSPACE_BITS` can't fit (or, at
+/// the top end, can't leave room for a wraparound id).
+const MAX_KEYSPACE_BITS: u32 = 15;
+
+/// User-configurable settings, loaded from a TOML file at startup and
+/// falling back to sensible defaults for anything missing or unreadable.
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Number of bits in the keyspace; the ring has `2^keyspace_bits` ids.
+    /// Clamped to `1..=MAX_KEYSPACE_BITS` on load.
+    pub keyspace_bits: u32,
+    pub colors: ColorsConfig,
+    pub marker: MarkerKind,
+    pub keybindings: KeybindingsConfig,
+}
+
+impl Config {
+    /// Load configuration from `path`, falling back to [`Config::default`]
+    /// if the file is missing or fails to parse. `keyspace_bits` is clamped
+    /// to a range that fits in `Chord`'s `u16` ids.
+    pub fn load(path: &Path) -> Self {
+        let mut config: Self = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default();
+        config.keyspace_bits = config.keyspace_bits.clamp(1, MAX_KEYSPACE_BITS);
+        config
+    }
+
+    /// The keyspace size (`2^keyspace_bits`) threaded through `Chord`.
+    pub fn max_id(&self) -> u16 {
+        1u16 << self.keyspace_bits
+    }
+
+    pub fn ring_color(&self) -> Color {
+        self.colors.ring.parse().unwrap_or(Color::Blue)
+    }
+
+    pub fn number_color(&self) -> Color {
+        self.colors.number.parse().unwrap_or(Color::Green)
+    }
+
+    pub fn highlight_color(&self) -> Color {
+        self.colors.highlight.parse().unwrap_or(Color::Yellow)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            keyspace_bits: 6,
+            colors: ColorsConfig::default(),
+            marker: MarkerKind::default(),
+            keybindings: KeybindingsConfig::default(),
+        }
+    }
+}
+
+/// Canvas colors, as strings parsed via `ratatui::style::Color`'s `FromStr`
+/// (named colors like `"blue"` or hex strings like `"#ff8800"`).
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct ColorsConfig {
+    pub ring: String,
+    pub number: String,
+    pub highlight: String,
+}
+
+impl Default for ColorsConfig {
+    fn default() -> Self {
+        Self {
+            ring: "blue".to_string(),
+            number: "green".to_string(),
+            highlight: "yellow".to_string(),
+        }
+    }
+}
+
+/// The ring canvas's point-rendering style.
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkerKind {
+    #[default]
+    Dot,
+    Braille,
+    Block,
+}
+
+impl MarkerKind {
+    pub fn to_marker(self) -> Marker {
+        match self {
+            MarkerKind::Dot => Marker::Dot,
+            MarkerKind::Braille => Marker::Braille,
+            MarkerKind::Block => Marker::Block,
+        }
+    }
+}
+
+/// Normal-mode key bindings for the app's core actions.
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct KeybindingsConfig {
+    pub add: char,
+    pub delete: char,
+    pub search: char,
+    pub insert: char,
+    pub get: char,
+    pub quit: char,
+}
+
+impl Default for KeybindingsConfig {
+    fn default() -> Self {
+        Self {
+            add: 'a',
+            delete: 'd',
+            search: 's',
+            insert: 'i',
+            get: 'g',
+            quit: 'q',
+        }
+    }
+}
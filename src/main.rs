@@ -1,18 +1,66 @@
 mod chord;
+mod config;
 
 use std::f64::consts::PI;
+use std::path::Path;
 
-use chord::{Chord, ChordError, MAX_ID};
+use chord::{Chord, ChordError};
+use config::Config;
 
 use color_eyre::Result;
 use ratatui::{
-    crossterm::event::{self, Event, KeyCode, KeyEventKind}, layout::{Constraint, Layout, Position, Rect}, style::{Color, Modifier, Style, Stylize}, symbols::Marker, text::{Line, Text}, widgets::{canvas::Canvas, Block, Paragraph, Widget}, DefaultTerminal, Frame
+    crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers}, layout::{Constraint, Layout, Position, Rect}, style::{Color, Modifier, Style, Stylize}, text::{Line, Span, Text}, widgets::{canvas::Canvas, Block, Paragraph, Widget}, DefaultTerminal, Frame
 };
 
+/// Path to the optional TOML configuration file, relative to the working directory.
+const CONFIG_PATH: &str = "chord.toml";
+
+/// Maximum number of entries kept per input-mode history.
+const HISTORY_CAPACITY: usize = 20;
+
+/// Ring buffer of previously submitted input values for one input mode,
+/// recalled with the Up/Down arrow keys like a shell prompt.
+struct History {
+    entries: Vec<String>,
+    capacity: usize,
+}
+
+impl History {
+    const fn new(capacity: usize) -> Self {
+        Self { entries: Vec::new(), capacity }
+    }
+
+    /// Record a submitted entry, skipping empty input and consecutive
+    /// duplicates, and dropping the oldest entry once over capacity.
+    fn push(&mut self, entry: String) {
+        if entry.is_empty() || self.entries.last().is_some_and(|last| last == &entry) {
+            return;
+        }
+        self.entries.push(entry);
+        if self.entries.len() > self.capacity {
+            self.entries.remove(0);
+        }
+    }
+
+    /// The entry `steps_back` submissions before the most recent one.
+    fn get(&self, steps_back: usize) -> Option<&str> {
+        let len = self.entries.len();
+        if steps_back >= len {
+            return None;
+        }
+        Some(self.entries[len - 1 - steps_back].as_str())
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
 fn main() -> Result<()> {
     color_eyre::install()?;
+    let config = Config::load(Path::new(CONFIG_PATH));
     let terminal = ratatui::init();
-    let app_result = App::new().run(terminal);
+    let app_result = App::new(config).run(terminal);
     ratatui::restore();
     app_result
 }
@@ -29,24 +77,105 @@ struct App {
     chord: Chord,
     /// Lookup and deletion message
     result: String,
-    marker: Marker,
+    /// Keyspace size, colors, canvas marker and key bindings
+    config: Config,
+    /// Previously searched keys, recalled with Up/Down in Searching mode
+    search_history: History,
+    /// Previously deleted node ids, recalled with Up/Down in Deleting mode
+    delete_history: History,
+    /// Previously inserted "key value" entries, recalled with Up/Down in Inserting mode
+    insert_history: History,
+    /// Previously retrieved keys, recalled with Up/Down in Getting mode
+    get_history: History,
+    /// Position within the current mode's history while browsing, reset on mode switch and submit
+    history_cursor: Option<usize>,
+    /// Node ids visited by the most recent lookup, oldest hop first
+    lookup_path: Vec<u16>,
+    /// How many nodes of `lookup_path` are currently revealed by the hop animation
+    lookup_step: usize,
 }
 
 enum InputMode {
     Normal,
     Searching,
     Deleting,
+    Inserting,
+    Getting,
 }
 
 impl App {
-    const fn new() -> Self {
+    fn new(config: Config) -> Self {
         Self {
             input: String::new(),
             input_mode: InputMode::Normal,
             result: String::new(),
             character_index: 0,
-            chord: Chord::new(),
-            marker: Marker::Dot,
+            chord: Chord::new(config.max_id()),
+            config,
+            search_history: History::new(HISTORY_CAPACITY),
+            delete_history: History::new(HISTORY_CAPACITY),
+            insert_history: History::new(HISTORY_CAPACITY),
+            get_history: History::new(HISTORY_CAPACITY),
+            history_cursor: None,
+            lookup_path: Vec::new(),
+            lookup_step: 0,
+        }
+    }
+
+    /// Reveal one more hop of the current lookup's path animation.
+    fn advance_lookup_animation(&mut self) {
+        if self.lookup_step < self.lookup_path.len() {
+            self.lookup_step += 1;
+        }
+    }
+
+    /// The history ring buffer for the current input mode.
+    fn history_for_mode(&mut self) -> &mut History {
+        match self.input_mode {
+            InputMode::Searching => &mut self.search_history,
+            InputMode::Deleting => &mut self.delete_history,
+            InputMode::Inserting => &mut self.insert_history,
+            InputMode::Getting => &mut self.get_history,
+            InputMode::Normal => unreachable!("no history outside of Searching/Deleting/Inserting/Getting"),
+        }
+    }
+
+    /// Recall an older entry from the current mode's history into the input
+    /// field, moving the cursor to end-of-line.
+    fn recall_older(&mut self) {
+        let next = match self.history_cursor {
+            None => 0,
+            Some(c) => c + 1,
+        };
+        let history = self.history_for_mode();
+        if next >= history.len() {
+            return;
+        }
+        if let Some(entry) = history.get(next) {
+            self.input = entry.to_string();
+            self.history_cursor = Some(next);
+            self.character_index = self.input.chars().count();
+        }
+    }
+
+    /// Recall a newer entry from the current mode's history into the input
+    /// field, clearing the input once the most recent entry is passed.
+    fn recall_newer(&mut self) {
+        match self.history_cursor {
+            None => {}
+            Some(0) => {
+                self.history_cursor = None;
+                self.input.clear();
+                self.reset_cursor();
+            }
+            Some(c) => {
+                let next = c - 1;
+                if let Some(entry) = self.history_for_mode().get(next) {
+                    self.input = entry.to_string();
+                    self.history_cursor = Some(next);
+                    self.character_index = self.input.chars().count();
+                }
+            }
         }
     }
 
@@ -114,20 +243,34 @@ impl App {
             None => {0}
         };
 
-        match self.chord.search(key) {
-            Ok(node) => {
-                self.result = format!("Key {} is located at node: {}", key, node.id);
+        match self.chord.search_with_path(key) {
+            Ok((node, path)) => {
+                let stored = self.chord.keys_on(node.id);
+                self.result = if stored.is_empty() {
+                    format!("Key {} is located at node: {}", key, node.id)
+                } else {
+                    format!("Key {} is located at node: {} (stores: {})", key, node.id, stored.join(", "))
+                };
+                self.lookup_path = path;
+                self.lookup_step = if self.lookup_path.is_empty() { 0 } else { 1 };
             }
             Err(e) => {
+                self.lookup_path.clear();
+                self.lookup_step = 0;
                 match e {
                     ChordError::OutOfRange => {
                         self.result = format!("Key {} is out of range of the chord ring.", key);
                     }
+                    ChordError::NoNodesExist => {
+                        self.result = "Cannot look up a key: the ring is empty.".to_string();
+                    }
                     _ => { panic!("Unhandled error in submit_query: {:?}", e); }
                 }
             }
         };
 
+        self.search_history.push(self.input.clone());
+        self.history_cursor = None;
         self.input.clear();
         self.reset_cursor();
     }
@@ -139,8 +282,12 @@ impl App {
         };
 
         match self.chord.delete_node(node_id) {
-            Ok(()) => {
-                self.result = format!("Node {} deleted", node_id);
+            Ok(migrated) => {
+                self.result = if migrated > 0 {
+                    format!("Node {} deleted ({} keys migrated)", node_id, migrated)
+                } else {
+                    format!("Node {} deleted", node_id)
+                };
             }
             Err(e) => {
                 match e {
@@ -152,6 +299,55 @@ impl App {
             }
         };
 
+        self.delete_history.push(self.input.clone());
+        self.history_cursor = None;
+        self.input.clear();
+        self.reset_cursor();
+    }
+
+    fn submit_insertion(&mut self) {
+        let trimmed = self.input.trim();
+        let (key, value) = match trimmed.split_once(' ') {
+            Some((key, value)) => (key, value.trim()),
+            None => (trimmed, ""),
+        };
+
+        match self.chord.put(key, value.to_string()) {
+            Ok(()) => {
+                self.result = format!("Key \"{}\" stored", key);
+            }
+            Err(e) => {
+                match e {
+                    ChordError::NoNodesExist => {
+                        self.result = "Cannot store a key: the ring is empty.".to_string();
+                    }
+                    ChordError::OutOfRange => {
+                        self.result = format!("Key \"{}\" hashes out of range of the chord ring.", key);
+                    }
+                    _ => { panic!("Unhandled error in submit_insertion: {:?}", e); }
+                }
+            }
+        };
+
+        self.insert_history.push(self.input.clone());
+        self.history_cursor = None;
+        self.input.clear();
+        self.reset_cursor();
+    }
+
+    fn submit_get(&mut self) {
+        let key = self.input.trim();
+
+        self.result = match self.chord.get(key) {
+            Ok(value) => format!("Key \"{}\" = \"{}\"", key, value),
+            Err(e) => match e {
+                ChordError::KeyDoesNotExist => format!("Key \"{}\" not found.", key),
+                _ => panic!("Unhandled error in submit_get: {:?}", e),
+            },
+        };
+
+        self.get_history.push(self.input.clone());
+        self.history_cursor = None;
         self.input.clear();
         self.reset_cursor();
     }
@@ -165,23 +361,56 @@ impl App {
             if let Event::Key(key) = event::read()? {
                 match self.input_mode {
                     InputMode::Normal => match key.code {
-                        KeyCode::Char('s') => {
+                        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            self.result = match self.chord.redo() {
+                                Some(description) => format!("redid: {}", description),
+                                None => "Nothing to redo.".to_string(),
+                            };
+                        }
+                        KeyCode::Char(c) if c == self.config.keybindings.search => {
                             self.input_mode = InputMode::Searching;
+                            self.history_cursor = None;
                         }
-                        KeyCode::Char('a') => {
+                        KeyCode::Char(c) if c == self.config.keybindings.add => {
                             self.result = match self.chord.add_node() {
-                                Ok(node_id) => {
-                                    format!("Node {} added", node_id)
+                                Ok((node_id, migrated)) => {
+                                    if migrated > 0 {
+                                        format!("Node {} added ({} keys migrated)", node_id, migrated)
+                                    } else {
+                                        format!("Node {} added", node_id)
+                                    }
                                 }
                                 Err(e) => {
                                     format!("Node add error: {:?}", e)
                                 }
                             }
                         }
-                        KeyCode::Char('d') => {
+                        KeyCode::Char(c) if c == self.config.keybindings.delete => {
                             self.input_mode = InputMode::Deleting;
+                            self.history_cursor = None;
+                        }
+                        KeyCode::Char(c) if c == self.config.keybindings.insert => {
+                            self.input_mode = InputMode::Inserting;
+                            self.history_cursor = None;
                         }
-                        KeyCode::Char('q') => {
+                        KeyCode::Char(c) if c == self.config.keybindings.get => {
+                            self.input_mode = InputMode::Getting;
+                            self.history_cursor = None;
+                        }
+                        KeyCode::Char('u') => {
+                            self.result = match self.chord.undo() {
+                                Some(description) => format!("undid: {}", description),
+                                None => "Nothing to undo.".to_string(),
+                            };
+                        }
+                        KeyCode::Char('t') => {
+                            self.chord.tick();
+                            self.result = "ring stabilized one step".to_string();
+                        }
+                        KeyCode::Char('n') => {
+                            self.advance_lookup_animation();
+                        }
+                        KeyCode::Char(c) if c == self.config.keybindings.quit => {
                             return Ok(());
                         }
                         _ => {}
@@ -192,6 +421,8 @@ impl App {
                         KeyCode::Backspace => self.delete_char(),
                         KeyCode::Left => self.move_cursor_left(),
                         KeyCode::Right => self.move_cursor_right(),
+                        KeyCode::Up => self.recall_older(),
+                        KeyCode::Down => self.recall_newer(),
                         KeyCode::Esc => self.input_mode = InputMode::Normal,
                         _ => {}
                     },
@@ -201,10 +432,34 @@ impl App {
                         KeyCode::Backspace => self.delete_char(),
                         KeyCode::Left => self.move_cursor_left(),
                         KeyCode::Right => self.move_cursor_right(),
+                        KeyCode::Up => self.recall_older(),
+                        KeyCode::Down => self.recall_newer(),
                         KeyCode::Esc => self.input_mode = InputMode::Normal,
                         _ => {}
                     },
-                    InputMode::Searching | InputMode::Deleting => {}
+                    InputMode::Inserting if key.kind == KeyEventKind::Press => match key.code {
+                        KeyCode::Enter => self.submit_insertion(),
+                        KeyCode::Char(to_insert) => self.enter_char(to_insert),
+                        KeyCode::Backspace => self.delete_char(),
+                        KeyCode::Left => self.move_cursor_left(),
+                        KeyCode::Right => self.move_cursor_right(),
+                        KeyCode::Up => self.recall_older(),
+                        KeyCode::Down => self.recall_newer(),
+                        KeyCode::Esc => self.input_mode = InputMode::Normal,
+                        _ => {}
+                    },
+                    InputMode::Getting if key.kind == KeyEventKind::Press => match key.code {
+                        KeyCode::Enter => self.submit_get(),
+                        KeyCode::Char(to_insert) => self.enter_char(to_insert),
+                        KeyCode::Backspace => self.delete_char(),
+                        KeyCode::Left => self.move_cursor_left(),
+                        KeyCode::Right => self.move_cursor_right(),
+                        KeyCode::Up => self.recall_older(),
+                        KeyCode::Down => self.recall_newer(),
+                        KeyCode::Esc => self.input_mode = InputMode::Normal,
+                        _ => {}
+                    },
+                    InputMode::Searching | InputMode::Deleting | InputMode::Inserting | InputMode::Getting => {}
                 }
             }
         }
@@ -228,17 +483,15 @@ impl App {
 
         let (msg, style) = match self.input_mode {
             InputMode::Normal => (
-                vec![
-                    "Press ".into(),
-                    "q".bold(),
-                    " to exit, ".into(),
-                    "s".bold(),
-                    " to lookup node, ".into(),
-                    "a".bold(),
-                    " to add node, ".into(),
-                    "d".bold(),
-                    " to delete node".into(),
-                ],
+                vec![format!(
+                    "Press {} to exit, {} to lookup node, {} to add node, {} to delete node, {} to insert a key, {} to get a key, u/Ctrl-r to undo/redo, t to stabilize, n to step the lookup animation",
+                    self.config.keybindings.quit,
+                    self.config.keybindings.search,
+                    self.config.keybindings.add,
+                    self.config.keybindings.delete,
+                    self.config.keybindings.insert,
+                    self.config.keybindings.get,
+                ).into()],
                 Style::default().add_modifier(Modifier::RAPID_BLINK),
             ),
             InputMode::Searching => (
@@ -247,7 +500,7 @@ impl App {
                     "Esc".bold(),
                     " to return, ".into(),
                     "Enter".bold(),
-                    format!(" to lookup key (0-{})", MAX_ID-1).into(),
+                    format!(" to lookup key (0-{})", self.chord.max_id() - 1).into(),
                 ],
                 Style::default(),
             ),
@@ -257,7 +510,27 @@ impl App {
                     "Esc".bold(),
                     " to return, ".into(),
                     "Enter".bold(),
-                    format!(" to delete node (0-{})", MAX_ID-1).into(),
+                    format!(" to delete node (0-{})", self.chord.max_id() - 1).into(),
+                ],
+                Style::default(),
+            ),
+            InputMode::Inserting => (
+                vec![
+                    "Press ".into(),
+                    "Esc".bold(),
+                    " to return, ".into(),
+                    "Enter".bold(),
+                    " to insert \"key value\"".into(),
+                ],
+                Style::default(),
+            ),
+            InputMode::Getting => (
+                vec![
+                    "Press ".into(),
+                    "Esc".bold(),
+                    " to return, ".into(),
+                    "Enter".bold(),
+                    " to get a key's value".into(),
                 ],
                 Style::default(),
             ),
@@ -271,6 +544,8 @@ impl App {
                 InputMode::Normal => Style::default(),
                 InputMode::Searching => Style::default().fg(Color::Yellow),
                 InputMode::Deleting => Style::default().fg(Color::Red),
+                InputMode::Inserting => Style::default().fg(Color::Green),
+                InputMode::Getting => Style::default().fg(Color::Cyan),
             })
             .block(Block::bordered().title("Input"));
         frame.render_widget(input, input_area);
@@ -281,7 +556,7 @@ impl App {
             // Make the cursor visible and ask ratatui to put it at the specified coordinates after
             // rendering
             #[allow(clippy::cast_possible_truncation)]
-            InputMode::Searching | InputMode::Deleting => frame.set_cursor_position(Position::new(
+            InputMode::Searching | InputMode::Deleting | InputMode::Inserting | InputMode::Getting => frame.set_cursor_position(Position::new(
                 // Draw the cursor at the current position in the input field.
                 // This position is can be controlled via the left and right arrow key
                 input_area.x + self.character_index as u16 + 1,
@@ -300,28 +575,81 @@ impl App {
         let center_y = (area.height / 2) as f64;
         let radius = (area.width.min(area.height) as f64 / 2.5).min(40.0);  // Adjust radius dynamically based on terminal size
 
+        let ring_color = self.config.ring_color();
+        let number_color = self.config.number_color();
+        let highlight_color = self.config.highlight_color();
+
         Canvas::default()
             .block(Block::bordered().title("Chord Ring"))
-            .marker(self.marker)
+            .marker(self.config.marker.to_marker())
             .paint(move |ctx| {
                 // Draw the circle
                 ctx.draw(&ratatui::widgets::canvas::Circle {
                     x: center_x,
                     y: center_y,
                     radius,
-                    color: Color::Blue,
+                    color: ring_color,
                 });
 
-                // Draw numbers around the circle
+                // Draw numbers around the circle, remembering each node's
+                // position so successor/predecessor links can be drawn below.
                 let mut ring = self.chord.get_ring().clone();
                 let num_pos = ring.len();
+                let mut positions = std::collections::HashMap::with_capacity(num_pos);
                 for i in 0..num_pos {
                     let angle = 2.0 * PI * (i as f64) / num_pos as f64;
                     let x_offset = ((radius*1.1) * angle.cos()) + center_x;
                     let y_offset = ((radius*1.1) * angle.sin()) + center_y;
 
-                    // Draw the number at the calculated position
-                    ctx.print(x_offset, y_offset, format!("{}", ring.pop().unwrap()).green());
+                    let id = *ring.pop().unwrap();
+                    positions.insert(id, (x_offset, y_offset));
+                    ctx.print(x_offset, y_offset, Span::styled(format!("{}", id), Style::default().fg(number_color)));
+                }
+
+                // Draw each node's successor link in the highlight color and
+                // its predecessor link in the ring color, so the two can be
+                // told apart while the ring is still stabilizing after churn.
+                for node in self.chord.nodes() {
+                    let Some(&(nx, ny)) = positions.get(&node.id) else { continue };
+
+                    if let Some(&(sx, sy)) = positions.get(&node.successor) {
+                        ctx.draw(&ratatui::widgets::canvas::Line {
+                            x1: nx, y1: ny, x2: sx, y2: sy,
+                            color: highlight_color,
+                        });
+                    }
+
+                    if let Some(&(px, py)) = node.predecessor.and_then(|id| positions.get(&id)) {
+                        ctx.draw(&ratatui::widgets::canvas::Line {
+                            x1: nx, y1: ny, x2: px, y2: py,
+                            color: ring_color,
+                        });
+                    }
+                }
+
+                // Animate the most recent lookup's hop path: earlier hops
+                // fade to gray, the newest revealed hop is drawn in the
+                // highlight color, and the final responsible node is
+                // re-printed in bold once fully revealed.
+                let visible = self.lookup_step.min(self.lookup_path.len());
+                for i in 0..visible.saturating_sub(1) {
+                    let (Some(&(x1, y1)), Some(&(x2, y2))) = (
+                        positions.get(&self.lookup_path[i]),
+                        positions.get(&self.lookup_path[i + 1]),
+                    ) else {
+                        continue;
+                    };
+                    let color = if i == visible - 2 { highlight_color } else { Color::DarkGray };
+                    ctx.draw(&ratatui::widgets::canvas::Line { x1, y1, x2, y2, color });
+                }
+                if visible > 0 && visible == self.lookup_path.len() {
+                    if let Some(&(x, y)) = positions.get(&self.lookup_path[visible - 1]) {
+                        let final_id = self.lookup_path[visible - 1];
+                        ctx.print(x, y, Span::styled(
+                            format!("{}", final_id),
+                            Style::default().fg(highlight_color).add_modifier(Modifier::BOLD),
+                        ));
+                    }
                 }
             })
             .x_bounds([area.x as f64, (area.width) as f64])